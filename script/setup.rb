@@ -167,3 +167,33 @@ def prepend(
 
   partial
 end
+
+# Moves all entries from one file to another i.e. when extracting modules into a new crate
+def rename_file(from:, to:)
+  TEMPLATES[to] = TEMPLATES.delete(from)
+  File.delete(from)
+  write_rust_file(filename: to)
+
+  ""
+end
+
+# Removes entries matching `match` under `key` i.e. `remove(filename: "src/lib.rs", key: :mod, match: /mod shared;/)`
+def remove(filename:, key:, match:)
+  index = TEMPLATES[filename][key].find_index { |entry| entry.match?(match) }
+  raise "No match for /#{match}/ in #{filename} #{key}" unless index
+
+  TEMPLATES[filename][key].delete_at(index)
+  write_rust_file(filename: filename)
+
+  ""
+end
+
+# Replaces text in every entry of a file i.e. changing `pub(crate)` to `pub`
+def gsub(filename:, pattern:, replacement:)
+  TEMPLATES[filename].each_value do |entries|
+    entries.map! { |entry| entry.gsub(pattern, replacement) }
+  end
+  write_rust_file(filename: filename)
+
+  ""
+end